@@ -1,5 +1,12 @@
 //! Keep track of the minimum or maximum value in a sliding window.
 //!
+//! The crate uses the standard library through the `std` feature, which is
+//! on by default; disable default features to build it as `no_std`. With
+//! default features disabled only
+//! [`alloc`] is required. Enabling the `inline` feature additionally exposes
+//! `InlineMovingAggregate`, a fixed-capacity variant backed by an inline
+//! buffer that performs no heap allocation at all.
+//!
 //! `moving min max` provides one data structure for keeping track of the
 //! minimum value and one for keeping track of the maximum value in a sliding
 //! window.
@@ -54,74 +61,123 @@
 //! assert_eq!(moving_max.max(), None);
 //! assert_eq!(moving_max.pop(), None);
 //! ```
+#![cfg_attr(not(feature = "std"), no_std)]
 
-/// `MovingMin` provides O(1) access to the minimum of a sliding window.
-pub struct MovingMin<T> {
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// An associative binary operation over `T` (a semigroup).
+///
+/// Implementors fold two aggregates into one. The operation must be
+/// associative; it does **not** need to be commutative, which is why the
+/// window always folds the oldest side first (see [`MovingAggregate::query`]).
+pub trait Semigroup<T> {
+    /// Combines `left` and `right`, where `left` holds the older elements of
+    /// the window and `right` the newer ones.
+    fn combine(left: &T, right: &T) -> T;
+}
+
+/// Selects the smaller of two values; the semigroup behind [`MovingMin`].
+pub struct Min;
+
+impl<T: Clone + PartialOrd> Semigroup<T> for Min {
+    fn combine(left: &T, right: &T) -> T {
+        if left < right {
+            left.clone()
+        } else {
+            right.clone()
+        }
+    }
+}
+
+/// Selects the larger of two values; the semigroup behind [`MovingMax`].
+pub struct Max;
+
+impl<T: Clone + PartialOrd> Semigroup<T> for Max {
+    fn combine(left: &T, right: &T) -> T {
+        if left > right {
+            left.clone()
+        } else {
+            right.clone()
+        }
+    }
+}
+
+/// `MovingAggregate` provides amortized O(1) access to the fold of an
+/// associative operation `Op` over a sliding FIFO window.
+///
+/// Each stack entry stores `(value, running_aggregate)`, where the aggregate
+/// is the fold of every element from that entry down to the bottom of its
+/// stack. On `pop` the push-stack is flipped into the pop-stack while the
+/// aggregates are recomputed bottom-up, so querying only has to combine the
+/// two stack tops.
+pub struct MovingAggregate<T, Op> {
     push_stack: Vec<(T, T)>,
     pop_stack: Vec<(T, T)>,
+    _op: PhantomData<Op>,
 }
 
-impl<T: Clone + PartialOrd> MovingMin<T> {
-    /// Creates a new `MovingMin` to keep track of the minimum in a sliding
-    /// window.
+impl<T: Clone, Op: Semigroup<T>> MovingAggregate<T, Op> {
+    /// Creates a new `MovingAggregate`.
     pub fn new() -> Self {
         Self {
             push_stack: Vec::new(),
             pop_stack: Vec::new(),
+            _op: PhantomData,
         }
     }
 
-    /// Creates a new `MovingMin` to keep track of the minimum in a sliding
-    /// window with `capacity` allocated slots.
+    /// Creates a new `MovingAggregate` with `capacity` allocated slots.
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             push_stack: Vec::with_capacity(capacity),
             pop_stack: Vec::with_capacity(capacity),
+            _op: PhantomData,
         }
     }
 
-    /// Returns the minimum of the sliding window or `None` if the window is
+    /// Returns the aggregate of the sliding window or `None` if the window is
     /// empty.
-    pub fn min(&self) -> Option<&T> {
-        match (self.push_stack.last(), self.pop_stack.last()) {
+    ///
+    /// The pop-stack holds the older half of the window, so the fold is always
+    /// `combine(pop_stack_top, push_stack_top)` to preserve FIFO order for
+    /// non-commutative operations.
+    pub fn query(&self) -> Option<T> {
+        match (self.pop_stack.last(), self.push_stack.last()) {
             (None, None) => None,
-            (Some((_, min)), None) => Some(min),
-            (None, Some((_, min))) => Some(min),
-            (Some((_, a)), Some((_, b))) => Some(if a < b { a } else { b }),
+            (Some((_, agg)), None) => Some(agg.clone()),
+            (None, Some((_, agg))) => Some(agg.clone()),
+            (Some((_, a)), Some((_, b))) => Some(Op::combine(a, b)),
         }
     }
 
     /// Pushes a new element into the sliding window.
     pub fn push(&mut self, val: T) {
-        self.push_stack.push(match self.push_stack.last() {
-            Some((_, min)) => {
-                if val > *min {
-                    (val, min.clone())
-                } else {
-                    (val.clone(), val)
-                }
-            }
-            None => (val.clone(), val),
-        });
+        let agg = match self.push_stack.last() {
+            Some((_, prev)) => Op::combine(prev, &val),
+            None => val.clone(),
+        };
+        self.push_stack.push((val, agg));
     }
 
-    /// Removes and returns the last value of the sliding window.
+    /// Removes and returns the oldest value of the sliding window.
     pub fn pop(&mut self) -> Option<T> {
         if self.pop_stack.is_empty() {
             match self.push_stack.pop() {
                 Some((val, _)) => {
-                    self.pop_stack.push((val.clone(), val));
+                    let agg = val.clone();
+                    self.pop_stack.push((val, agg));
                     while let Some((val, _)) = self.push_stack.pop() {
                         // This is save, because we just pushed one element onto
                         // pop_stack and therefore it cannot be empty.
                         let last =
                             unsafe { self.pop_stack.get_unchecked(self.pop_stack.len() - 1) };
-                        let min = if last.1 < val {
-                            last.1.clone()
-                        } else {
-                            val.clone()
-                        };
-                        self.pop_stack.push((val.clone(), min));
+                        // The incoming element is older than everything already
+                        // on the pop_stack, so it folds in on the left.
+                        let agg = Op::combine(&val, &last.1);
+                        self.pop_stack.push((val, agg));
                     }
                 }
                 None => return None,
@@ -134,25 +190,508 @@ impl<T: Clone + PartialOrd> MovingMin<T> {
     pub fn len(&self) -> usize {
         self.push_stack.len() + self.pop_stack.len()
     }
+
+    /// Returns `true` if the sliding window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.push_stack.is_empty() && self.pop_stack.is_empty()
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.pop_stack
+            .last()
+            .or_else(|| self.push_stack.first())
+            .map(|(val, _)| val)
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    ///
+    /// This is an alias for [`front`](Self::front).
+    pub fn peek(&self) -> Option<&T> {
+        self.front()
+    }
+
+    /// Returns an iterator over the window elements in FIFO order, from the
+    /// element `pop` would yield next to the most recently pushed one.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.pop_stack.iter().rev().chain(self.push_stack.iter()),
+        }
+    }
+
+    /// Returns the running aggregates on top of the pop- and push-stack.
+    fn top_aggregates(&self) -> (Option<&T>, Option<&T>) {
+        (
+            self.pop_stack.last().map(|(_, agg)| agg),
+            self.push_stack.last().map(|(_, agg)| agg),
+        )
+    }
+}
+
+impl<T: Clone, Op: Semigroup<T>> Default for MovingAggregate<T, Op> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, Op: Semigroup<T>> FromIterator<T> for MovingAggregate<T, Op> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut agg = Self::new();
+        agg.extend(iter);
+        agg
+    }
+}
+
+impl<T: Clone, Op: Semigroup<T>> Extend<T> for MovingAggregate<T, Op> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push(val);
+        }
+    }
+}
+
+/// An iterator over the elements of a sliding window in FIFO order.
+///
+/// It walks the `pop_stack` in reverse and then the `push_stack` forward,
+/// which reflects the true insertion order of the flipped-stack
+/// representation. Created by [`MovingAggregate::iter`].
+pub struct Iter<'a, T> {
+    inner: IterInner<'a, T>,
+}
+
+/// The chained iterator backing [`Iter`]: the `pop_stack` reversed, followed
+/// by the `push_stack` in order.
+type IterInner<'a, T> = core::iter::Chain<
+    core::iter::Rev<core::slice::Iter<'a, (T, T)>>,
+    core::slice::Iter<'a, (T, T)>,
+>;
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(val, _)| val)
+    }
+}
+
+#[cfg(feature = "inline")]
+pub use inline::{CapacityError, InlineMovingAggregate};
+
+#[cfg(feature = "inline")]
+mod inline {
+    use super::Semigroup;
+    use core::marker::PhantomData;
+    use core::mem::MaybeUninit;
+    use core::ptr;
+
+    /// Error returned by [`InlineMovingAggregate::push`] when the window
+    /// already holds `CAP` elements.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CapacityError;
+
+    impl core::fmt::Display for CapacityError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("sliding window is at capacity")
+        }
+    }
+
+    /// A fixed-capacity, allocation-free stack used to back the inline
+    /// aggregate.
+    struct InlineVec<T, const CAP: usize> {
+        buf: [MaybeUninit<T>; CAP],
+        len: usize,
+    }
+
+    impl<T, const CAP: usize> InlineVec<T, CAP> {
+        const fn new() -> Self {
+            Self {
+                // This is save, because an array of `MaybeUninit` does not
+                // require any of its elements to be initialized.
+                buf: unsafe { MaybeUninit::uninit().assume_init() },
+                len: 0,
+            }
+        }
+
+        fn push(&mut self, val: T) -> Result<(), CapacityError> {
+            if self.len == CAP {
+                return Err(CapacityError);
+            }
+            self.buf[self.len] = MaybeUninit::new(val);
+            self.len += 1;
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            if self.len == 0 {
+                return None;
+            }
+            self.len -= 1;
+            // This is save, because the slot was initialized by a previous
+            // push and is not read again until overwritten.
+            Some(unsafe { self.buf[self.len].as_ptr().read() })
+        }
+
+        fn last(&self) -> Option<&T> {
+            self.buf[..self.len]
+                .last()
+                // This is save, because the first `len` slots are initialized.
+                .map(|slot| unsafe { &*slot.as_ptr() })
+        }
+
+        fn first(&self) -> Option<&T> {
+            self.buf[..self.len]
+                .first()
+                // This is save, because the first `len` slots are initialized.
+                .map(|slot| unsafe { &*slot.as_ptr() })
+        }
+
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+            // This is save, because the first `len` slots are initialized.
+            self.buf[..self.len]
+                .iter()
+                .map(|slot| unsafe { &*slot.as_ptr() })
+        }
+    }
+
+    impl<T, const CAP: usize> Drop for InlineVec<T, CAP> {
+        fn drop(&mut self) {
+            for slot in &mut self.buf[..self.len] {
+                // This is save, because these slots are initialized and each
+                // is dropped exactly once.
+                unsafe { ptr::drop_in_place(slot.as_mut_ptr()) };
+            }
+        }
+    }
+
+    /// `InlineMovingAggregate` is an allocation-free variant of
+    /// [`MovingAggregate`](super::MovingAggregate) that backs its two stacks
+    /// with inline buffers instead of a `Vec`.
+    ///
+    /// `CAP` bounds the total number of elements the window may hold at once.
+    /// It offers the same amortized O(1) sliding-window fold, but runs with
+    /// zero heap allocation, which makes it suitable for embedded targets.
+    /// Because the capacity is bounded, [`push`](Self::push) returns
+    /// [`CapacityError`] instead of growing once the window holds `CAP`
+    /// elements.
+    pub struct InlineMovingAggregate<T, Op, const CAP: usize> {
+        push_stack: InlineVec<(T, T), CAP>,
+        pop_stack: InlineVec<(T, T), CAP>,
+        _op: PhantomData<Op>,
+    }
+
+    impl<T: Clone, Op: Semigroup<T>, const CAP: usize> InlineMovingAggregate<T, Op, CAP> {
+        /// Creates a new empty `InlineMovingAggregate`.
+        pub const fn new() -> Self {
+            Self {
+                push_stack: InlineVec::new(),
+                pop_stack: InlineVec::new(),
+                _op: PhantomData,
+            }
+        }
+
+        /// Returns the aggregate of the sliding window or `None` if the window
+        /// is empty.
+        pub fn query(&self) -> Option<T> {
+            match (self.pop_stack.last(), self.push_stack.last()) {
+                (None, None) => None,
+                (Some((_, agg)), None) => Some(agg.clone()),
+                (None, Some((_, agg))) => Some(agg.clone()),
+                (Some((_, a)), Some((_, b))) => Some(Op::combine(a, b)),
+            }
+        }
+
+        /// Pushes a new element into the sliding window, returning
+        /// [`CapacityError`] if the window is already full.
+        pub fn push(&mut self, val: T) -> Result<(), CapacityError> {
+            if self.len() == CAP {
+                return Err(CapacityError);
+            }
+            let agg = match self.push_stack.last() {
+                Some((_, prev)) => Op::combine(prev, &val),
+                None => val.clone(),
+            };
+            // `len() < CAP` implies the push-stack has a free slot, so this
+            // cannot overflow.
+            self.push_stack.push((val, agg))
+        }
+
+        /// Removes and returns the oldest value of the sliding window.
+        pub fn pop(&mut self) -> Option<T> {
+            if self.pop_stack.is_empty() {
+                match self.push_stack.pop() {
+                    Some((val, _)) => {
+                        let agg = val.clone();
+                        // The pop_stack is empty and has the same capacity as
+                        // the push_stack, so these pushes cannot overflow.
+                        let _ = self.pop_stack.push((val, agg));
+                        while let Some((val, _)) = self.push_stack.pop() {
+                            let last = self
+                                .pop_stack
+                                .last()
+                                .expect("pop_stack cannot be empty here");
+                            // The incoming element is older than everything
+                            // already on the pop_stack, so it folds in on the
+                            // left.
+                            let agg = Op::combine(&val, &last.1);
+                            let _ = self.pop_stack.push((val, agg));
+                        }
+                    }
+                    None => return None,
+                }
+            }
+            self.pop_stack.pop().map(|(val, _)| val)
+        }
+
+        /// Returns the number of elements stored in the sliding window.
+        pub fn len(&self) -> usize {
+            self.push_stack.len() + self.pop_stack.len()
+        }
+
+        /// Returns `true` if the sliding window is empty.
+        pub fn is_empty(&self) -> bool {
+            self.push_stack.is_empty() && self.pop_stack.is_empty()
+        }
+
+        /// Returns the next element `pop` would yield without removing it, or
+        /// `None` if the window is empty.
+        pub fn front(&self) -> Option<&T> {
+            self.pop_stack
+                .last()
+                .or_else(|| self.push_stack.first())
+                .map(|(val, _)| val)
+        }
+
+        /// Returns an iterator over the window elements in FIFO order.
+        pub fn iter(&self) -> impl Iterator<Item = &T> {
+            self.pop_stack
+                .iter()
+                .rev()
+                .chain(self.push_stack.iter())
+                .map(|(val, _)| val)
+        }
+    }
+
+    impl<T: Clone, Op: Semigroup<T>, const CAP: usize> Default
+        for InlineMovingAggregate<T, Op, CAP>
+    {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+/// `MovingMin` provides O(1) access to the minimum of a sliding window.
+pub struct MovingMin<T> {
+    inner: MovingAggregate<T, Min>,
+}
+
+impl<T: Clone + PartialOrd> MovingMin<T> {
+    /// Creates a new `MovingMin` to keep track of the minimum in a sliding
+    /// window.
+    pub fn new() -> Self {
+        Self {
+            inner: MovingAggregate::new(),
+        }
+    }
+
+    /// Creates a new `MovingMin` to keep track of the minimum in a sliding
+    /// window with `capacity` allocated slots.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: MovingAggregate::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the minimum of the sliding window or `None` if the window is
+    /// empty.
+    pub fn min(&self) -> Option<&T> {
+        match self.inner.top_aggregates() {
+            (None, None) => None,
+            (Some(min), None) | (None, Some(min)) => Some(min),
+            (Some(a), Some(b)) => Some(if a < b { a } else { b }),
+        }
+    }
+
+    /// Pushes a new element into the sliding window.
+    pub fn push(&mut self, val: T) {
+        self.inner.push(val);
+    }
+
+    /// Removes and returns the last value of the sliding window.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    /// Returns the number of elements stored in the sliding window.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the sliding window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.inner.front()
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    ///
+    /// This is an alias for [`front`](Self::front).
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.peek()
+    }
+
+    /// Returns an iterator over the window elements in FIFO order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.inner.iter()
+    }
+}
+
+impl<T: Clone + PartialOrd> Default for MovingMin<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + PartialOrd> FromIterator<T> for MovingMin<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            inner: MovingAggregate::from_iter(iter),
+        }
+    }
+}
+
+impl<T: Clone + PartialOrd> Extend<T> for MovingMin<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
 }
 
 /// `MovingMax` provides O(1) access to the maximum of a sliding window.
 pub struct MovingMax<T> {
-    push_stack: Vec<(T, T)>,
-    pop_stack: Vec<(T, T)>,
+    inner: MovingAggregate<T, Max>,
 }
 
 impl<T: Clone + PartialOrd> MovingMax<T> {
     /// Creates a new `MovingMax` to keep track of the maximum in a sliding window.
     pub fn new() -> Self {
         Self {
-            push_stack: Vec::new(),
-            pop_stack: Vec::new(),
+            inner: MovingAggregate::new(),
         }
     }
 
     /// Creates a new `MovingMax` to keep track of the maximum in a sliding window with
     /// `capacity` allocated slots.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: MovingAggregate::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum of the sliding window or `None` if the window is empty.
+    pub fn max(&self) -> Option<&T> {
+        match self.inner.top_aggregates() {
+            (None, None) => None,
+            (Some(max), None) | (None, Some(max)) => Some(max),
+            (Some(a), Some(b)) => Some(if a > b { a } else { b }),
+        }
+    }
+
+    /// Pushes a new element into the sliding window.
+    pub fn push(&mut self, val: T) {
+        self.inner.push(val);
+    }
+
+    /// Removes and returns the last value of the sliding window.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    /// Returns the number of elements stored in the sliding window.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the sliding window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.inner.front()
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    ///
+    /// This is an alias for [`front`](Self::front).
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.peek()
+    }
+
+    /// Returns an iterator over the window elements in FIFO order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.inner.iter()
+    }
+}
+
+impl<T: Clone + PartialOrd> Default for MovingMax<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + PartialOrd> FromIterator<T> for MovingMax<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            inner: MovingAggregate::from_iter(iter),
+        }
+    }
+}
+
+impl<T: Clone + PartialOrd> Extend<T> for MovingMax<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+/// `MovingMinMax` provides O(1) access to both the minimum and the maximum of
+/// a single sliding window, maintaining both extrema with one set of stacks
+/// instead of two separate structures.
+pub struct MovingMinMax<T> {
+    push_stack: Vec<(T, T, T)>,
+    pop_stack: Vec<(T, T, T)>,
+}
+
+impl<T: Clone + PartialOrd> MovingMinMax<T> {
+    /// Creates a new `MovingMinMax` to keep track of the minimum and maximum in
+    /// a sliding window.
+    pub fn new() -> Self {
+        Self {
+            push_stack: Vec::new(),
+            pop_stack: Vec::new(),
+        }
+    }
+
+    /// Creates a new `MovingMinMax` to keep track of the minimum and maximum in
+    /// a sliding window with `capacity` allocated slots.
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             push_stack: Vec::with_capacity(capacity),
@@ -160,64 +699,255 @@ impl<T: Clone + PartialOrd> MovingMax<T> {
         }
     }
 
-    /// Returns the maximum of the sliding window or `None` if the window is empty.
+    /// Returns the minimum of the sliding window or `None` if the window is
+    /// empty.
+    pub fn min(&self) -> Option<&T> {
+        match (self.pop_stack.last(), self.push_stack.last()) {
+            (None, None) => None,
+            (Some((_, min, _)), None) | (None, Some((_, min, _))) => Some(min),
+            (Some((_, a, _)), Some((_, b, _))) => Some(if a < b { a } else { b }),
+        }
+    }
+
+    /// Returns the maximum of the sliding window or `None` if the window is
+    /// empty.
     pub fn max(&self) -> Option<&T> {
-        match (self.push_stack.last(), self.pop_stack.last()) {
+        match (self.pop_stack.last(), self.push_stack.last()) {
+            (None, None) => None,
+            (Some((_, _, max)), None) | (None, Some((_, _, max))) => Some(max),
+            (Some((_, _, a)), Some((_, _, b))) => Some(if a > b { a } else { b }),
+        }
+    }
+
+    /// Returns the minimum and maximum of the sliding window or `None` if the
+    /// window is empty.
+    pub fn min_max(&self) -> Option<(&T, &T)> {
+        match (self.pop_stack.last(), self.push_stack.last()) {
             (None, None) => None,
-            (Some((_, max)), None) => Some(max),
-            (None, Some((_, max))) => Some(max),
-            (Some((_, a)), Some((_, b))) => Some(if a > b { a } else { b }),
+            (Some((_, min, max)), None) | (None, Some((_, min, max))) => Some((min, max)),
+            (Some((_, a_min, a_max)), Some((_, b_min, b_max))) => Some((
+                if a_min < b_min { a_min } else { b_min },
+                if a_max > b_max { a_max } else { b_max },
+            )),
         }
     }
 
     /// Pushes a new element into the sliding window.
     pub fn push(&mut self, val: T) {
-        self.push_stack.push(match self.push_stack.last() {
-            Some((_, max)) => {
-                if val < *max {
-                    (val, max.clone())
-                } else {
-                    (val.clone(), val)
-                }
-            }
-            None => (val.clone(), val),
-        });
+        let (min, max) = match self.push_stack.last() {
+            Some((_, min, max)) => (
+                if val < *min { val.clone() } else { min.clone() },
+                if val > *max { val.clone() } else { max.clone() },
+            ),
+            None => (val.clone(), val.clone()),
+        };
+        self.push_stack.push((val, min, max));
     }
 
     /// Removes and returns the last value of the sliding window.
     pub fn pop(&mut self) -> Option<T> {
         if self.pop_stack.is_empty() {
             match self.push_stack.pop() {
-                Some((val, _)) => {
-                    self.pop_stack.push((val.clone(), val));
-                    while let Some((val, _)) = self.push_stack.pop() {
+                Some((val, _, _)) => {
+                    self.pop_stack.push((val.clone(), val.clone(), val));
+                    while let Some((val, _, _)) = self.push_stack.pop() {
                         // This is save, because we just pushed one element onto
                         // pop_stack and therefore it cannot be empty.
                         let last =
                             unsafe { self.pop_stack.get_unchecked(self.pop_stack.len() - 1) };
-                        let max = if last.1 > val {
-                            last.1.clone()
-                        } else {
-                            val.clone()
-                        };
-                        self.pop_stack.push((val.clone(), max));
+                        let min = if last.1 < val { last.1.clone() } else { val.clone() };
+                        let max = if last.2 > val { last.2.clone() } else { val.clone() };
+                        self.pop_stack.push((val, min, max));
                     }
                 }
                 None => return None,
             }
         }
-        self.pop_stack.pop().map(|(val, _)| val)
+        self.pop_stack.pop().map(|(val, _, _)| val)
     }
 
     /// Returns the number of elements stored in the sliding window.
     pub fn len(&self) -> usize {
         self.push_stack.len() + self.pop_stack.len()
     }
+
+    /// Returns `true` if the sliding window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.push_stack.is_empty() && self.pop_stack.is_empty()
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.pop_stack
+            .last()
+            .or_else(|| self.push_stack.first())
+            .map(|(val, _, _)| val)
+    }
+
+    /// Returns the next element `pop` would yield without removing it, or
+    /// `None` if the window is empty.
+    ///
+    /// This is an alias for [`front`](Self::front).
+    pub fn peek(&self) -> Option<&T> {
+        self.front()
+    }
+
+    /// Returns an iterator over the window elements in FIFO order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.pop_stack
+            .iter()
+            .rev()
+            .chain(self.push_stack.iter())
+            .map(|(val, _, _)| val)
+    }
+}
+
+impl<T: Clone + PartialOrd> FromIterator<T> for MovingMinMax<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut min_max = Self::new();
+        min_max.extend(iter);
+        min_max
+    }
+}
+
+impl<T: Clone + PartialOrd> Extend<T> for MovingMinMax<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push(val);
+        }
+    }
+}
+
+impl<T: Clone + PartialOrd> Default for MovingMinMax<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `MovingMinWindow` keeps track of the minimum over the last `N` pushed
+/// elements, automatically evicting the oldest element once the window is
+/// full.
+pub struct MovingMinWindow<T, const N: usize> {
+    inner: MovingMin<T>,
+}
+
+impl<T: Clone + PartialOrd, const N: usize> MovingMinWindow<T, N> {
+    /// Creates a new `MovingMinWindow` holding at most `N` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N == 0`, which cannot describe a meaningful window.
+    pub fn new() -> Self {
+        assert!(N > 0, "window size N must be greater than zero");
+        Self {
+            inner: MovingMin::with_capacity(N),
+        }
+    }
+
+    /// Pushes a new element into the window.
+    ///
+    /// Once the window holds `N` elements the oldest element is evicted and
+    /// returned, so that `min` always reflects exactly the last `N` pushes.
+    pub fn push(&mut self, val: T) -> Option<T> {
+        let evicted = if self.inner.len() == N {
+            self.inner.pop()
+        } else {
+            None
+        };
+        self.inner.push(val);
+        evicted
+    }
+
+    /// Returns the minimum of the window or `None` if the window is empty.
+    pub fn min(&self) -> Option<&T> {
+        self.inner.min()
+    }
+
+    /// Returns the number of elements stored in the window.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T: Clone + PartialOrd, const N: usize> Default for MovingMinWindow<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `MovingMaxWindow` keeps track of the maximum over the last `N` pushed
+/// elements, automatically evicting the oldest element once the window is
+/// full.
+pub struct MovingMaxWindow<T, const N: usize> {
+    inner: MovingMax<T>,
+}
+
+impl<T: Clone + PartialOrd, const N: usize> MovingMaxWindow<T, N> {
+    /// Creates a new `MovingMaxWindow` holding at most `N` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N == 0`, which cannot describe a meaningful window.
+    pub fn new() -> Self {
+        assert!(N > 0, "window size N must be greater than zero");
+        Self {
+            inner: MovingMax::with_capacity(N),
+        }
+    }
+
+    /// Pushes a new element into the window.
+    ///
+    /// Once the window holds `N` elements the oldest element is evicted and
+    /// returned, so that `max` always reflects exactly the last `N` pushes.
+    pub fn push(&mut self, val: T) -> Option<T> {
+        let evicted = if self.inner.len() == N {
+            self.inner.pop()
+        } else {
+            None
+        };
+        self.inner.push(val);
+        evicted
+    }
+
+    /// Returns the maximum of the window or `None` if the window is empty.
+    pub fn max(&self) -> Option<&T> {
+        self.inner.max()
+    }
+
+    /// Returns the number of elements stored in the window.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T: Clone + PartialOrd, const N: usize> Default for MovingMaxWindow<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    // The std prelude is unavailable under `--no-default-features`, so the
+    // tests pull these in from `alloc` explicitly.
+    #[cfg(not(feature = "std"))]
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
 
     #[test]
     fn moving_min() {
@@ -274,4 +1004,154 @@ mod tests {
         assert_eq!(moving_max.max(), None);
         assert_eq!(moving_max.pop(), None);
     }
+
+    struct Concat;
+
+    impl Semigroup<String> for Concat {
+        fn combine(left: &String, right: &String) -> String {
+            let mut s = String::with_capacity(left.len() + right.len());
+            s.push_str(left);
+            s.push_str(right);
+            s
+        }
+    }
+
+    #[test]
+    fn moving_aggregate_preserves_fifo_order() {
+        // Concatenation is associative but not commutative, so the window has
+        // to fold the oldest element first.
+        let mut agg = MovingAggregate::<String, Concat>::new();
+        agg.push("a".to_string());
+        agg.push("b".to_string());
+        agg.push("c".to_string());
+        assert_eq!(agg.query(), Some("abc".to_string()));
+
+        // A pop flips the stacks; the remaining window must stay in order.
+        assert_eq!(agg.pop(), Some("a".to_string()));
+        assert_eq!(agg.query(), Some("bc".to_string()));
+        agg.push("d".to_string());
+        assert_eq!(agg.query(), Some("bcd".to_string()));
+        assert_eq!(agg.pop(), Some("b".to_string()));
+        assert_eq!(agg.query(), Some("cd".to_string()));
+    }
+
+    #[test]
+    fn from_iter_extend_and_iter() {
+        let mut moving_min = MovingMin::from_iter([3, 1, 2]);
+        assert_eq!(moving_min.len(), 3);
+        assert!(!moving_min.is_empty());
+        assert_eq!(moving_min.min(), Some(&1));
+        assert_eq!(moving_min.front(), Some(&3));
+        assert_eq!(moving_min.peek(), Some(&3));
+        assert_eq!(moving_min.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2]);
+
+        // A pop flips the stacks; iteration must still reflect FIFO order.
+        assert_eq!(moving_min.pop(), Some(3));
+        moving_min.extend([5, 4]);
+        assert_eq!(moving_min.iter().copied().collect::<Vec<_>>(), vec![1, 2, 5, 4]);
+        assert_eq!(moving_min.front(), Some(&1));
+
+        let empty = MovingMax::<i32>::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.front(), None);
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[cfg(feature = "inline")]
+    #[test]
+    fn inline_moving_aggregate() {
+        let mut agg = InlineMovingAggregate::<i32, Min, 3>::new();
+        assert!(agg.is_empty());
+        assert_eq!(agg.push(2), Ok(()));
+        assert_eq!(agg.push(1), Ok(()));
+        assert_eq!(agg.push(3), Ok(()));
+        assert_eq!(agg.query(), Some(1));
+        assert_eq!(agg.front(), Some(&2));
+        assert_eq!(agg.iter().copied().collect::<Vec<_>>(), vec![2, 1, 3]);
+
+        // The window is full, so a further push reports the overflow.
+        assert_eq!(agg.push(4), Err(CapacityError));
+
+        assert_eq!(agg.pop(), Some(2));
+        assert_eq!(agg.query(), Some(1));
+        assert_eq!(agg.push(0), Ok(()));
+        assert_eq!(agg.query(), Some(0));
+
+        // The pop flipped the stacks; refilling the push-stack must still
+        // respect the total capacity rather than each stack's own `CAP`.
+        assert_eq!(agg.len(), 3);
+        assert_eq!(agg.push(7), Err(CapacityError));
+    }
+
+    #[test]
+    fn moving_min_max() {
+        let mut moving_min_max = MovingMinMax::from_iter([2, 1, 3]);
+        assert_eq!(moving_min_max.min(), Some(&1));
+        assert_eq!(moving_min_max.max(), Some(&3));
+        assert_eq!(moving_min_max.min_max(), Some((&1, &3)));
+        assert_eq!(moving_min_max.front(), Some(&2));
+        assert_eq!(moving_min_max.peek(), Some(&2));
+        assert_eq!(moving_min_max.iter().copied().collect::<Vec<_>>(), vec![2, 1, 3]);
+
+        // A pop flips the stacks; iteration must still reflect FIFO order.
+        assert_eq!(moving_min_max.pop(), Some(2));
+        moving_min_max.extend([4]);
+        assert_eq!(moving_min_max.iter().copied().collect::<Vec<_>>(), vec![1, 3, 4]);
+        assert_eq!(moving_min_max.pop(), Some(1));
+        assert_eq!(moving_min_max.pop(), Some(3));
+        assert_eq!(moving_min_max.pop(), Some(4));
+        assert_eq!(moving_min_max.min(), None);
+
+        let mut moving_min_max = MovingMinMax::<i32>::new();
+        moving_min_max.push(2);
+        moving_min_max.push(1);
+        moving_min_max.push(3);
+
+        assert_eq!(moving_min_max.pop(), Some(2));
+        assert_eq!(moving_min_max.min_max(), Some((&1, &3)));
+        assert_eq!(moving_min_max.pop(), Some(1));
+        assert_eq!(moving_min_max.min_max(), Some((&3, &3)));
+        assert_eq!(moving_min_max.pop(), Some(3));
+        assert_eq!(moving_min_max.min(), None);
+        assert_eq!(moving_min_max.max(), None);
+        assert_eq!(moving_min_max.min_max(), None);
+    }
+
+    #[test]
+    fn moving_min_window() {
+        let mut window = MovingMinWindow::<i32, 3>::new();
+        assert_eq!(window.push(5), None);
+        assert_eq!(window.push(3), None);
+        assert_eq!(window.push(4), None);
+        assert_eq!(window.min(), Some(&3));
+        assert_eq!(window.len(), 3);
+
+        // The window is full, so pushing evicts the oldest element.
+        assert_eq!(window.push(2), Some(5));
+        assert_eq!(window.min(), Some(&2));
+        assert_eq!(window.push(6), Some(3));
+        assert_eq!(window.min(), Some(&2));
+        assert_eq!(window.push(1), Some(4));
+        assert_eq!(window.min(), Some(&1));
+        assert_eq!(window.len(), 3);
+    }
+
+    #[test]
+    fn moving_max_window() {
+        let mut window = MovingMaxWindow::<i32, 3>::new();
+        assert_eq!(window.push(1), None);
+        assert_eq!(window.push(3), None);
+        assert_eq!(window.push(2), None);
+        assert_eq!(window.max(), Some(&3));
+        assert_eq!(window.len(), 3);
+
+        // The window is full, so pushing evicts the oldest element.
+        assert_eq!(window.push(5), Some(1));
+        assert_eq!(window.max(), Some(&5));
+        assert_eq!(window.push(4), Some(3));
+        assert_eq!(window.max(), Some(&5));
+        assert_eq!(window.push(0), Some(2));
+        assert_eq!(window.max(), Some(&5));
+        assert_eq!(window.len(), 3);
+    }
 }